@@ -90,75 +90,319 @@ fn calculate_3d_col_major<T>(
     unsafe { base.add(offset) }
 }
 
-// h3 -- N-Dimensional Array Address Calculation (Row-Major)
-// h4 -- Generic function for n-dimensional arrays using row-major ordering
-// h4 -- Handles arrays with any number of dimensions dynamically
-// h5 -- base: Raw pointer to the start of the n-dimensional array
-// h5 -- indices: Slice reference (&[usize]) containing indices for each dimension
-// h5 -- dimensions: Slice reference containing sizes of each dimension
-// h5 -- Returns: Pointer to calculated address using generalized row-major formula
-// h6 -- Formula: Σ (indices[dim] * Π dimensions[next_dim] for next_dim > dim)
-// h6 -- For 4D: offset = i*(d1*d2*d3) + j*(d2*d3) + k*(d3) + l
-// h6 -- Rust slices (&[T]) are fat pointers containing both data pointer and length
-fn calculate_nd_row_major<T>(base: *const T, indices: &[usize], dimensions: &[usize]) -> *const T {
-    let n = indices.len();
-    let mut offset = 0;
-
-    // h4 -- Calculate offset using nested loops
-    // h5 -- Outer loop: Process each dimension from left to right
-    // h5 -- Inner loop: Calculate multiplier for current dimension
-    for dim in 0..n {
-        let mut multiplier = 1;
-        for next_dim in dim + 1..n {
-            multiplier *= dimensions[next_dim];
+// h3 -- N-Dimensional Array Descriptor (APL-style "weight vector")
+// h4 -- Owning array type that stores its stride vector instead of recomputing
+// h4 -- dimension multipliers on every address calculation
+// h5 -- rank: Number of dimensions
+// h5 -- dims: Size of each dimension
+// h5 -- strides: The "weight vector" - signed per-dimension multiplier
+// h5 -- data: The owned, flat element buffer
+// h6 -- Keeping strides as stored state (rather than recomputing) is what later
+// h6 -- unlocks cheap transpose, slicing, and non-contiguous views
+struct NdArray<T> {
+    rank: usize,
+    dims: Vec<usize>,
+    strides: Vec<isize>,
+    data: Vec<T>,
+}
+
+// h3 -- Array Ordering
+// h4 -- Selects which stride-initialization routine `NdArray::zeros` uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Order {
+    RowMajor,
+    ColMajor,
+}
+
+impl<T: Clone + Default> NdArray<T> {
+    // h4 -- Builds the weight vector for a given ordering without touching data
+    // h5 -- Row-major: strides[d] = product of dims[d+1..] (rightmost varies fastest)
+    // h5 -- Column-major: strides[d] = product of dims[..d] (leftmost varies fastest)
+    fn strides_for(dims: &[usize], order: Order) -> Vec<isize> {
+        let n = dims.len();
+        let mut strides = vec![0isize; n];
+        match order {
+            Order::RowMajor => {
+                for d in 0..n {
+                    let multiplier: usize = dims[d + 1..].iter().product();
+                    strides[d] = multiplier as isize;
+                }
+            }
+            Order::ColMajor => {
+                for d in 0..n {
+                    let multiplier: usize = dims[..d].iter().product();
+                    strides[d] = multiplier as isize;
+                }
+            }
         }
-        offset += indices[dim] * multiplier;
+        strides
     }
 
-    unsafe { base.add(offset) }
+    // h3 -- Construct a zero-filled NdArray with the requested ordering
+    fn zeros(dims: Vec<usize>, order: Order) -> Self {
+        let rank = dims.len();
+        let strides = Self::strides_for(&dims, order);
+        let len: usize = dims.iter().product();
+        NdArray {
+            rank,
+            dims,
+            strides,
+            data: vec![T::default(); len],
+        }
+    }
+
+    // h4 -- The single address formula: offset = Σ indices[d] * strides[d]
+    // h6 -- Signed arithmetic so this also works once strides can be negative
+    // h6 -- (reversed views) or non-contiguous (sliced/transposed views)
+    fn offset(&self, indices: &[usize]) -> usize {
+        let signed: isize = indices
+            .iter()
+            .zip(self.strides.iter())
+            .map(|(&i, &s)| i as isize * s)
+            .sum();
+        signed as usize
+    }
+
+    fn get(&self, indices: &[usize]) -> &T {
+        &self.data[self.offset(indices)]
+    }
+
+    fn get_mut(&mut self, indices: &[usize]) -> &mut T {
+        let off = self.offset(indices);
+        &mut self.data[off]
+    }
+
+    // h4 -- Borrow this array as a view so transpose/permute/reshape/slice can
+    // h4 -- rewrite dims/strides/base without ever moving element data
+    fn view(&self) -> NdArrayView<'_, T> {
+        NdArrayView {
+            dims: self.dims.clone(),
+            strides: self.strides.clone(),
+            base: 0,
+            data: &self.data,
+        }
+    }
 }
 
-// h3 -- N-Dimensional Array Address Calculation (Column-Major)
-// h4 -- Generic function for n-dimensional arrays using column-major ordering
-// h4 -- Handles arrays with any number of dimensions dynamically
-// h5 -- base: Raw pointer to the start of the n-dimensional array
-// h5 -- indices: Slice reference containing indices for each dimension
-// h5 -- dimensions: Slice reference containing sizes of each dimension
-// h5 -- Returns: Pointer to calculated address using generalized column-major formula
-// h6 -- Formula: Σ (indices[dim] * Π dimensions[prev_dim] for prev_dim > dim)
-// h6 -- For 4D: offset = l + k*d3 + j*d2*d3 + i*d1*d2*d3
-// h6 -- Note: Processes dimensions from right to left (rev() reverses iteration)
-fn calculate_nd_col_major<T>(base: *const T, indices: &[usize], dimensions: &[usize]) -> *const T {
-    let n = indices.len();
-    let mut offset = 0;
-
-    // h4 -- Calculate offset processing dimensions from right to left
-    // h5 -- (0..n).rev(): Iterate from last dimension to first
-    // h5 -- This matches column-major order: rightmost index in formula varies fastest
-    for dim in (0..n).rev() {
-        let mut multiplier = 1;
-        for prev_dim in (dim + 1..n).rev() {
-            multiplier *= dimensions[prev_dim];
+// h3 -- N-Dimensional Array View
+// h4 -- A borrowed window onto an NdArray's data: its own dims/strides/base,
+// h4 -- sharing the parent's buffer rather than copying it
+// h5 -- base: Signed starting offset into `data` (lets slicing/indexing advance it)
+// h6 -- Because views can be transposed or sliced, strides may be negative or
+// h6 -- non-contiguous, so address math here is signed (isize) throughout
+struct NdArrayView<'a, T> {
+    dims: Vec<usize>,
+    strides: Vec<isize>,
+    base: isize,
+    data: &'a [T],
+}
+
+impl<'a, T> NdArrayView<'a, T> {
+    fn offset(&self, indices: &[usize]) -> usize {
+        let signed: isize = self.base
+            + indices
+                .iter()
+                .zip(self.strides.iter())
+                .map(|(&i, &s)| i as isize * s)
+                .sum::<isize>();
+        signed as usize
+    }
+
+    fn get(&self, indices: &[usize]) -> &T {
+        &self.data[self.offset(indices)]
+    }
+
+    // h4 -- O(rank) transpose: reverses dims and strides together, no data movement
+    fn transpose(&self) -> NdArrayView<'a, T> {
+        let mut dims = self.dims.clone();
+        let mut strides = self.strides.clone();
+        dims.reverse();
+        strides.reverse();
+        NdArrayView {
+            dims,
+            strides,
+            base: self.base,
+            data: self.data,
         }
-        offset += indices[dim] * multiplier;
     }
 
-    unsafe { base.add(offset) }
+    // h4 -- O(rank) axis permutation: reorders dims/strides by `axes`, no data movement
+    // h5 -- axes: axes[new_axis] = old_axis, e.g. [2, 0, 1] rotates three axes
+    fn permute_axes(&self, axes: &[usize]) -> NdArrayView<'a, T> {
+        let dims = axes.iter().map(|&a| self.dims[a]).collect();
+        let strides = axes.iter().map(|&a| self.strides[a]).collect();
+        NdArrayView {
+            dims,
+            strides,
+            base: self.base,
+            data: self.data,
+        }
+    }
+
+    // h4 -- True only when this view walks memory as one contiguous row-major block
+    fn is_contiguous(&self) -> bool {
+        NdArray::<T>::strides_for_dims_only(&self.dims) == self.strides
+    }
+
+    // h4 -- Reshape succeeds only for contiguous arrays (product of dims must match);
+    // h4 -- it then recomputes fresh contiguous strides for the new shape
+    fn reshape(&self, new_dims: Vec<usize>) -> Result<NdArrayView<'a, T>, &'static str> {
+        let old_len: usize = self.dims.iter().product();
+        let new_len: usize = new_dims.iter().product();
+        if old_len != new_len {
+            return Err("reshape: element count mismatch");
+        }
+        if !self.is_contiguous() {
+            return Err("reshape: view is not contiguous");
+        }
+        let strides = NdArray::<T>::strides_for_dims_only(&new_dims);
+        Ok(NdArrayView {
+            dims: new_dims,
+            strides,
+            base: self.base,
+            data: self.data,
+        })
+    }
+
+    // h4 -- Drops one dimension: removes dims[axis]/strides[axis] and advances the
+    // h4 -- base offset by i * strides[axis], e.g. pulling a single column or row
+    // h4 -- out of a matrix as a rank-1 view
+    fn index_axis(&self, axis: usize, i: usize) -> NdArrayView<'a, T> {
+        let mut dims = self.dims.clone();
+        let mut strides = self.strides.clone();
+        let base = self.base + i as isize * strides[axis];
+        dims.remove(axis);
+        strides.remove(axis);
+        NdArrayView {
+            dims,
+            strides,
+            base,
+            data: self.data,
+        }
+    }
+
+    // h4 -- Keeps the axis but narrows dims[axis] to the range length and advances
+    // h4 -- the base offset by range.start * strides[axis]
+    fn slice_axis(&self, axis: usize, range: std::ops::Range<usize>) -> NdArrayView<'a, T> {
+        let mut dims = self.dims.clone();
+        let base = self.base + range.start as isize * self.strides[axis];
+        dims[axis] = range.end - range.start;
+        NdArrayView {
+            dims,
+            strides: self.strides.clone(),
+            base,
+            data: self.data,
+        }
+    }
+}
+
+impl<T> NdArray<T> {
+    // h4 -- Row-major contiguous strides for a shape, independent of element type
+    fn strides_for_dims_only(dims: &[usize]) -> Vec<isize> {
+        let n = dims.len();
+        let mut strides = vec![0isize; n];
+        for d in 0..n {
+            let multiplier: usize = dims[d + 1..].iter().product();
+            strides[d] = multiplier as isize;
+        }
+        strides
+    }
+}
+
+// h3 -- Compressed-Sparse-Column Matrix
+// h4 -- Column-compressed storage for large, mostly-empty 2D data - a smaller
+// h4 -- footprint and faster column traversal than the dense row-major NdArray
+// h5 -- col_ptr: Length cols+1; column j's nonzeros live in vals[col_ptr[j]..col_ptr[j+1]]
+// h5 -- row_idx: Row index of each stored nonzero, parallel to vals, sorted ascending per column
+// h5 -- vals: The nonzero values themselves
+struct CscMatrix<T> {
+    rows: usize,
+    cols: usize,
+    col_ptr: Vec<usize>,
+    row_idx: Vec<usize>,
+    vals: Vec<T>,
+}
+
+impl<T: Clone + Default + PartialEq> CscMatrix<T> {
+    // h4 -- Builds the CSC form from a dense rank-2 NdArray by scanning column by column
+    fn from_dense(dense: &NdArray<T>) -> Self {
+        assert_eq!(dense.rank, 2, "CscMatrix::from_dense expects a rank-2 NdArray");
+        let rows = dense.dims[0];
+        let cols = dense.dims[1];
+        let zero = T::default();
+
+        let mut col_ptr = Vec::with_capacity(cols + 1);
+        let mut row_idx = Vec::new();
+        let mut vals = Vec::new();
+        col_ptr.push(0);
+
+        for j in 0..cols {
+            for i in 0..rows {
+                let v = dense.get(&[i, j]);
+                if *v != zero {
+                    row_idx.push(i);
+                    vals.push(v.clone());
+                }
+            }
+            col_ptr.push(vals.len());
+        }
+
+        CscMatrix {
+            rows,
+            cols,
+            col_ptr,
+            row_idx,
+            vals,
+        }
+    }
+
+    // h4 -- Binary search within column j's row-index slice (kept sorted ascending)
+    fn get(&self, i: usize, j: usize) -> T {
+        let start = self.col_ptr[j];
+        let end = self.col_ptr[j + 1];
+        match self.row_idx[start..end].binary_search(&i) {
+            Ok(pos) => self.vals[start + pos].clone(),
+            Err(_) => T::default(),
+        }
+    }
+
+    fn nnz(&self) -> usize {
+        self.vals.len()
+    }
+
+    // h4 -- Yields (row, &value) pairs for every stored nonzero in column j
+    fn column_iter(&self, j: usize) -> impl Iterator<Item = (usize, &T)> {
+        let start = self.col_ptr[j];
+        let end = self.col_ptr[j + 1];
+        self.row_idx[start..end]
+            .iter()
+            .copied()
+            .zip(self.vals[start..end].iter())
+    }
+
+    // h4 -- Round-trips back to a dense row-major NdArray
+    fn to_dense(&self) -> NdArray<T> {
+        let mut dense = NdArray::zeros(vec![self.rows, self.cols], Order::RowMajor);
+        for j in 0..self.cols {
+            for (i, v) in self.column_iter(j) {
+                *dense.get_mut(&[i, j]) = v.clone();
+            }
+        }
+        dense
+    }
 }
 
 // h3 -- 2D Array Print Utility Function
-// h4 -- Helper function to display 2D array contents stored in a flat slice
+// h4 -- Helper function to display 2D array contents via an NdArrayView
 // h4 -- Demonstrates how multi-dimensional arrays are laid out in memory
-// h5 -- arr: Slice reference to the flat array data (&[f32])
-// h5 -- rows: Number of rows to display
-// h5 -- cols: Number of columns to display
-// h6 -- Uses row-major indexing: arr[i * cols + j] to access element [i][j]
-// h6 -- Rust's print! and println! macros provide formatted output
-fn print_array_2d(arr: &[f32], rows: usize, cols: usize) {
+// h5 -- view: Rank-2 NdArrayView over the data to display
+// h6 -- Goes through the descriptor's get() rather than assuming i*cols+j, so
+// h6 -- this also prints correctly for transposed or otherwise non-contiguous views
+fn print_array_2d(view: &NdArrayView<f32>, rows: usize, cols: usize) {
     println!("Array contents:");
     for i in 0..rows {
         for j in 0..cols {
-            print!("{:6.1}", arr[i * cols + j]);
+            print!("{:6.1}", view.get(&[i, j]));
         }
         println!();
     }
@@ -240,7 +484,17 @@ fn main() {
     // h6 -- This demonstrates that 2D arrays are contiguous in memory
     let flat_b: &[f32] =
         unsafe { std::slice::from_raw_parts(b.as_ptr() as *const f32, ROWS * COLS) };
-    print_array_2d(flat_b, ROWS, COLS);
+    let b_nd: NdArray<f32> = NdArray {
+        rank: 2,
+        dims: vec![ROWS, COLS],
+        strides: NdArray::<f32>::strides_for_dims_only(&[ROWS, COLS]),
+        data: flat_b.to_vec(),
+    };
+    print_array_2d(&b_nd.view(), ROWS, COLS);
+    println!(
+        "Transposed (view only, no data moved):\n{:?}",
+        b_nd.view().transpose().dims
+    );
 
     println!("\nBase address: {:p}", &b);
     println!("Dimensions: {} x {}", ROWS, COLS);
@@ -344,7 +598,7 @@ fn main() {
     }
 
     // h3 -- Section 4: N-Dimensional Array Demonstration
-    // h4 -- Generic n-dimensional array using dynamic vectors
+    // h4 -- Generic n-dimensional array using the strided NdArray descriptor
     println!("\n\n4. N-DIMENSIONAL ARRAY (GENERIC)");
     println!("================================");
 
@@ -353,16 +607,16 @@ fn main() {
     let dimensions: [usize; 4] = [2, 3, 4, 2];
     let test_indices: [usize; 4] = [1, 2, 3, 1];
 
-    // h4 -- Create flat vector to represent n-dimensional data
-    // h5 -- dimensions.iter().product(): Calculate total elements by multiplying dimensions
-    // h5 -- Vec::with_capacity(): Create vector with pre-allocated capacity
-    // h5 -- Vec<T>: Heap-allocated growable array (similar to ArrayList in other languages)
-    let total_elements: usize = dimensions.iter().product();
-    let mut d: Vec<f32> = Vec::with_capacity(total_elements);
+    // h4 -- Build both orderings of the same shape; the weight vector captures
+    // h4 -- everything that used to be recomputed inside calculate_nd_row_major
+    // h4 -- / calculate_nd_col_major
+    let mut row_major_nd: NdArray<f32> = NdArray::zeros(dimensions.to_vec(), Order::RowMajor);
+    let mut col_major_nd: NdArray<f32> = NdArray::zeros(dimensions.to_vec(), Order::ColMajor);
 
-    // h4 -- Initialize vector with predictable values
+    let total_elements: usize = dimensions.iter().product();
     for idx in 0..total_elements {
-        d.push(idx as f32 * 10.0);
+        row_major_nd.data[idx] = idx as f32 * 10.0;
+        col_major_nd.data[idx] = idx as f32 * 10.0;
     }
 
     // h4 -- Display array dimensions using iterator methods
@@ -387,32 +641,74 @@ fn main() {
     }
     println!("]");
 
-    // h4 -- Calculate actual index in flat array using row-major
-    let mut actual_index = 0;
-    let mut multiplier = 1;
-    for d in (0..dimensions.len()).rev() {
-        actual_index += test_indices[d] * multiplier;
-        multiplier *= dimensions[d];
-    }
+    // h5 -- Row-major lookup via the descriptor's own offset formula
+    println!("Row-major rank:    {}", row_major_nd.rank);
+    println!("Row-major strides: {:?}", row_major_nd.strides);
+    println!(
+        "Row-major value:   {:.1}",
+        row_major_nd.get(&test_indices)
+    );
+    println!(
+        "Row-major address: {:p}",
+        row_major_nd.get(&test_indices)
+    );
+
+    // h5 -- Column-major lookup via the descriptor's own offset formula
+    println!("Column-major strides: {:?}", col_major_nd.strides);
+    println!(
+        "Column-major value:   {:.1}",
+        col_major_nd.get(&test_indices)
+    );
+    println!(
+        "Column-major address: {:p}",
+        col_major_nd.get(&test_indices)
+    );
 
-    println!("Actual address:    {:p}", &d[actual_index]);
-    println!("Actual value:      {:.1}", d[actual_index]);
+    // h5 -- permute_axes reorders all axes at once; transpose is the rank-2 case
+    let permuted = row_major_nd.view().permute_axes(&[3, 1, 0, 2]);
+    println!("permute_axes([3, 1, 0, 2]) dims: {:?}", permuted.dims);
+    println!("Row-major view is_contiguous: {}", row_major_nd.view().is_contiguous());
 
-    // h5 -- N-dimensional row-major calculation
-    // h5 -- d.as_ptr(): Get raw pointer to vector data
-    // h5 -- &test_indices: Reference to fixed-size array (coerced to slice)
-    let row_major_nd = calculate_nd_row_major(d.as_ptr(), &test_indices, &dimensions);
-    println!("Row-major calc:    {:p}", row_major_nd);
-    unsafe {
-        println!("Row-major value:   {:.1}", *row_major_nd);
+    // h5 -- reshape only succeeds on a contiguous view; row_major_nd's own
+    // h5 -- view is freshly built and contiguous, so this round-trips cleanly
+    match row_major_nd.view().reshape(vec![total_elements]) {
+        Ok(flat) => println!("reshape({:?}) dims: {:?}", [total_elements], flat.dims),
+        Err(e) => println!("reshape failed: {}", e),
     }
 
-    // h5 -- N-dimensional column-major calculation
-    let col_major_nd = calculate_nd_col_major(d.as_ptr(), &test_indices, &dimensions);
-    println!("Column-major calc: {:p}", col_major_nd);
-    unsafe {
-        println!("Column-major value: {:.1}", *col_major_nd);
+    // h5 -- Column slice: a rank-3 view obtained by dropping the first axis,
+    // h5 -- sharing row_major_nd's buffer rather than copying it
+    let column_view = row_major_nd.view().index_axis(0, 1);
+    println!("Column slice (index_axis(0, 1)) dims: {:?}", column_view.dims);
+
+    // h5 -- Narrow one axis instead of dropping it: slice_axis keeps the rank
+    let narrowed = row_major_nd.view().slice_axis(1, 0..2);
+    println!("slice_axis(1, 0..2) dims: {:?}", narrowed.dims);
+
+    // h4 -- Compressed-sparse-column backend for mostly-empty dense data
+    let mut sparse_src: NdArray<f32> = NdArray::zeros(vec![4, 4], Order::RowMajor);
+    *sparse_src.get_mut(&[0, 1]) = 5.0;
+    *sparse_src.get_mut(&[2, 1]) = 7.0;
+    *sparse_src.get_mut(&[3, 3]) = 9.0;
+    let csc = CscMatrix::from_dense(&sparse_src);
+    println!(
+        "CSC matrix: {} nonzeros out of {} entries ({}x{})",
+        csc.nnz(),
+        csc.rows * csc.cols,
+        csc.rows,
+        csc.cols
+    );
+    println!("CSC get(2, 1): {:.1}", csc.get(2, 1));
+    print!("CSC column_iter(1): ");
+    for (row, val) in csc.column_iter(1) {
+        print!("({}, {:.1}) ", row, val);
     }
+    println!();
+    let roundtrip = csc.to_dense();
+    println!(
+        "CSC to_dense round-trip get(3, 3): {:.1}",
+        roundtrip.get(&[3, 3])
+    );
 
     // h3 -- Section 5: Access Pattern Demonstration
     // h4 -- Visual demonstration of memory layout patterns
@@ -481,4 +777,6 @@ fn main() {
     println!("  - References (&T, &mut T) are safe and checked at compile time");
     println!("  - as_ptr() method gets raw pointer, as_ref() gets reference");
     println!("  - Unsafe code is needed for low-level memory operations");
+    println!("  - NdArray<T> stores its stride (\"weight\") vector instead of");
+    println!("    recomputing dimension multipliers on every lookup");
 }
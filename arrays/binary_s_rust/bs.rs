@@ -4,29 +4,150 @@
 
 use std::time::{Duration, Instant};
 
+// h3 -- Binary Search By Comparator
+// h4 -- Core binary search loop expressed in terms of a caller-supplied
+// h4 -- comparator instead of Ord, so callers can search by a derived key
+// h4 -- without copying the whole element into a key type
+// h5 -- arr: Sorted slice reference (&[T])
+// h5 -- f: Comparator returning how the candidate element relates to the target
+// h6 -- Returns: Ok(i) if found at index i, Err(i) if not present but i is
+// h6 -- where an element would need to go to keep the slice sorted
+fn binary_search_by<T, F>(arr: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> std::cmp::Ordering,
+{
+    let mut low = 0;
+    let mut high = arr.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        match f(&arr[mid]) {
+            std::cmp::Ordering::Equal => return Ok(mid),
+            std::cmp::Ordering::Less => low = mid + 1,
+            std::cmp::Ordering::Greater => high = mid,
+        }
+    }
+    Err(low)
+}
+
+// h3 -- Binary Search By Derived Key
+// h4 -- Searches a slice sorted by some key `B` extracted from each element,
+// h4 -- without requiring the element itself to implement Ord
+// h5 -- arr: Sorted slice reference (&[T]), sorted by the key `f` extracts
+// h5 -- b: Key value to search for
+// h5 -- f: Extracts the comparison key from an element
+fn binary_search_by_key<T, B: Ord, F>(arr: &[T], b: &B, mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> B,
+{
+    binary_search_by(arr, |elem| f(elem).cmp(b))
+}
+
 // h3 -- Binary Search Function
 // h4 -- Searches for target in sorted slice using iterative approach
-// h5 -- arr: Sorted slice reference of integers (&[i32])
+// h4 -- Generic over any T: Ord, so this works with &str, String, tuples, or
+// h4 -- any custom type deriving Ord - not just i32
+// h5 -- arr: Sorted slice reference (&[T])
 // h5 -- target: Value to search for
 // h6 -- Returns: Option<usize> - Some(index) if found, None if not found
 // h6 -- Time Complexity: O(log n) - logarithmic time
 // h6 -- Space Complexity: O(1) - constant space
-// h6 -- Note: Uses Rust's Option type for safe error handling
-fn binary_search(arr: &[i32], target: i32) -> Option<usize> {
+// h6 -- Note: Uses Rust's Option type for safe error handling, delegating the
+// h6 -- actual search to binary_search_by with an Ord-based comparator
+fn binary_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    binary_search_by(arr, |elem| elem.cmp(target)).ok()
+}
+
+// h3 -- Binary Search With Insertion Point
+// h4 -- Mirrors the standard library's `Result<usize, usize>` contract instead
+// h4 -- of collapsing a miss to `None`, so callers can insert in place
+// h5 -- arr: Sorted slice reference of integers (&[i32])
+// h5 -- target: Value to search for
+// h6 -- Returns: Ok(i) if found at index i, Err(i) if not present but i is
+// h6 -- where target belongs to keep the slice sorted
+fn binary_search_insertion(arr: &[i32], target: i32) -> Result<usize, usize> {
+    binary_search_by(arr, |elem| elem.cmp(&target))
+}
+
+// h3 -- Lower Bound
+// h4 -- Finds the first index whose element is >= target, in O(log n), using
+// h4 -- a partition-point style loop rather than the find-or-miss contract
+// h4 -- above - this is what lets duplicates be handled correctly
+// h5 -- arr: Sorted slice reference of integers (&[i32])
+// h5 -- target: Value to search for
+// h6 -- Returns: arr.len() if every element is < target
+fn lower_bound(arr: &[i32], target: i32) -> usize {
     let mut low = 0;
-    let mut high = arr.len().checked_sub(1)?; // Handle empty array
+    let mut high = arr.len();
 
-    while low <= high {
-        // Calculate mid index without overflow
+    while low < high {
         let mid = low + (high - low) / 2;
+        if arr[mid] < target {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
 
-        match arr[mid].cmp(&target) {
-            std::cmp::Ordering::Equal => return Some(mid),
-            std::cmp::Ordering::Less => low = mid + 1,
-            std::cmp::Ordering::Greater => high = mid - 1,
+// h3 -- Upper Bound
+// h4 -- Finds the first index whose element is > target, in O(log n)
+// h5 -- arr: Sorted slice reference of integers (&[i32])
+// h5 -- target: Value to search for
+// h6 -- Returns: arr.len() if every element is <= target
+fn upper_bound(arr: &[i32], target: i32) -> usize {
+    let mut low = 0;
+    let mut high = arr.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if arr[mid] <= target {
+            low = mid + 1;
+        } else {
+            high = mid;
         }
     }
-    None
+    low
+}
+
+// h3 -- Equal Range
+// h4 -- The half-open span [lower_bound, upper_bound) of all elements equal
+// h4 -- to target - useful for counting duplicates in O(log n)
+fn equal_range(arr: &[i32], target: i32) -> (usize, usize) {
+    (lower_bound(arr, target), upper_bound(arr, target))
+}
+
+// h3 -- Branchless Binary Search Function
+// h4 -- Same contract as binary_search, but avoids the three-way `match` whose
+// h4 -- unpredictable branches dominate latency on L1/L2-resident slices
+// h5 -- arr: Sorted slice reference of integers (&[i32])
+// h5 -- target: Value to search for
+// h6 -- Returns: Option<usize> - Some(index) if found, None if not found
+// h6 -- Maintains base/size instead of low/high; each step probes the last
+// h6 -- element of the left half, `arr[base + half - 1]`, and conditionally
+// h6 -- advances base by half - the addition lowers to a conditional move
+// h6 -- rather than a branch, unlike the match-based low/high version above
+fn binary_search_branchless(arr: &[i32], target: i32) -> Option<usize> {
+    if arr.is_empty() {
+        return None;
+    }
+
+    let mut base = 0usize;
+    let mut size = arr.len();
+
+    while size > 1 {
+        let half = size / 2;
+        base += if arr[base + half - 1] < target { half } else { 0 };
+        size -= half;
+    }
+
+    if arr[base] == target {
+        Some(base)
+    } else {
+        None
+    }
 }
 
 // h3 -- Performance Test Function
@@ -58,30 +179,39 @@ fn performance_test(size: usize) {
 
     // Warm up the function
     for _ in 0..10 {
-        binary_search(&large_arr, large_arr[size / 2]);
+        binary_search(&large_arr, &large_arr[size / 2]);
+        binary_search_branchless(&large_arr, large_arr[size / 2]);
     }
 
-    // Test each case with multiple iterations
+    // Test each case with multiple iterations, branching vs branchless side by side
     const ITERATIONS: usize = 10000;
     for (t, &target) in targets.iter().enumerate() {
-        let mut total_duration = Duration::new(0, 0);
-        let mut found_count = 0;
+        let mut branching_duration = Duration::new(0, 0);
+        let mut branching_found = 0;
+        let mut branchless_duration = Duration::new(0, 0);
+        let mut branchless_found = 0;
 
         for _ in 0..ITERATIONS {
             let start = Instant::now();
-            let result = binary_search(&large_arr, target);
-            let elapsed = start.elapsed();
-            total_duration += elapsed;
+            let result = binary_search(&large_arr, &target);
+            branching_duration += start.elapsed();
+            if result.is_some() {
+                branching_found += 1;
+            }
 
+            let start = Instant::now();
+            let result = binary_search_branchless(&large_arr, target);
+            branchless_duration += start.elapsed();
             if result.is_some() {
-                found_count += 1;
+                branchless_found += 1;
             }
         }
 
-        let avg_duration = total_duration / ITERATIONS as u32;
+        let branching_avg = branching_duration / ITERATIONS as u32;
+        let branchless_avg = branchless_duration / ITERATIONS as u32;
         println!(
-            "  {} case: {:?} (success: {}/{})",
-            cases[t], avg_duration, found_count, ITERATIONS
+            "  {} case: branching {:?} (success: {}/{}), branchless {:?} (success: {}/{})",
+            cases[t], branching_avg, branching_found, ITERATIONS, branchless_avg, branchless_found, ITERATIONS
         );
     }
 }
@@ -93,28 +223,28 @@ fn validation_tests() {
 
     // Test case 1: Normal sorted array
     let arr1 = [2, 4, 6, 8, 10, 12, 14];
-    let result1 = binary_search(&arr1, 10);
+    let result1 = binary_search(&arr1, &10);
     println!(
         "  Search for 10 in {:?}: {:?} (expected: Some(4))",
         arr1, result1
     );
 
     // Test case 2: First element
-    let result2 = binary_search(&arr1, 2);
+    let result2 = binary_search(&arr1, &2);
     println!(
         "  Search for 2 (first element): {:?} (expected: Some(0))",
         result2
     );
 
     // Test case 3: Last element
-    let result3 = binary_search(&arr1, 14);
+    let result3 = binary_search(&arr1, &14);
     println!(
         "  Search for 14 (last element): {:?} (expected: Some(6))",
         result3
     );
 
     // Test case 4: Not found
-    let result4 = binary_search(&arr1, 5);
+    let result4 = binary_search(&arr1, &5);
     println!(
         "  Search for 5 (not present): {:?} (expected: None)",
         result4
@@ -122,28 +252,132 @@ fn validation_tests() {
 
     // Test case 5: Single element array
     let single_arr = [42];
-    let result5 = binary_search(&single_arr, 42);
+    let result5 = binary_search(&single_arr, &42);
     println!(
         "  Search in single element [42]: {:?} (expected: Some(0))",
         result5
     );
 
     // Test case 6: Single element not found
-    let result6 = binary_search(&single_arr, 99);
+    let result6 = binary_search(&single_arr, &99);
     println!("  Search for 99 in [42]: {:?} (expected: None)", result6);
 
     // Test case 7: Empty array
     let empty_arr: [i32; 0] = [];
-    let result7 = binary_search(&empty_arr, 5);
+    let result7 = binary_search(&empty_arr, &5);
     println!("  Search in empty array: {:?} (expected: None)", result7);
 
     // Test case 8: Large numbers
     let large_arr = [i32::MIN, -100, 0, 100, i32::MAX];
-    let result8 = binary_search(&large_arr, i32::MAX);
+    let result8 = binary_search(&large_arr, &i32::MAX);
     println!(
         "  Search for i32::MAX in large range: {:?} (expected: Some(4))",
         result8
     );
+
+    // Test case 9: Branchless variant agrees with the branching one
+    let arr9 = [2, 4, 6, 8, 10, 12, 14];
+    let result9 = binary_search_branchless(&arr9, 8);
+    println!(
+        "  Branchless search for 8 in {:?}: {:?} (expected: Some(3))",
+        arr9, result9
+    );
+    let result10 = binary_search_branchless(&arr9, 5);
+    println!(
+        "  Branchless search for 5 (not present): {:?} (expected: None)",
+        result10
+    );
+
+    // Test case 11: Insertion point on a miss, matching std's Result<usize, usize>
+    let arr11 = [1, 2, 4, 6, 8, 9];
+    let result11 = binary_search_insertion(&arr11, 5);
+    println!(
+        "  Insertion search for 5 in {:?}: {:?} (expected: Err(3))",
+        arr11, result11
+    );
+
+    // Test case 12: Insertion point past the last element
+    let arr12 = [1, 2, 4, 5, 6, 8];
+    let result12 = binary_search_insertion(&arr12, 9);
+    println!(
+        "  Insertion search for 9 in {:?}: {:?} (expected: Err(6))",
+        arr12, result12
+    );
+
+    // Test case 13: Found case still returns Ok with the matching index
+    let result13 = binary_search_insertion(&arr11, 8);
+    println!(
+        "  Insertion search for 8 in {:?}: {:?} (expected: Ok(4))",
+        arr11, result13
+    );
+
+    // Test case 14: Insertion point at the very start (target smaller than all elements)
+    let result14 = binary_search_insertion(&arr11, 0);
+    println!(
+        "  Insertion search for 0 in {:?}: {:?} (expected: Err(0))",
+        arr11, result14
+    );
+
+    // Test case 15: Generic over &str, not just i32
+    let words = ["apple", "banana", "cherry", "date", "fig"];
+    let result15 = binary_search(&words, &"cherry");
+    println!(
+        "  Search for \"cherry\" in {:?}: {:?} (expected: Some(2))",
+        words, result15
+    );
+
+    // Test case 16: Generic over a custom struct deriving Ord
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Version {
+        major: u32,
+        minor: u32,
+    }
+    let versions = [
+        Version { major: 1, minor: 0 },
+        Version { major: 1, minor: 5 },
+        Version { major: 2, minor: 0 },
+    ];
+    let target_version = Version { major: 1, minor: 5 };
+    let result16 = binary_search(&versions, &target_version);
+    println!(
+        "  Search for {:?} in version list: {:?} (expected: Some(1))",
+        target_version, result16
+    );
+
+    // Test case 17: binary_search_by_key searches records sorted by id, without
+    // requiring (id, name) itself to implement Ord
+    let records = [(1, "alice"), (3, "bob"), (7, "carol"), (12, "dave")];
+    let result17 = binary_search_by_key(&records, &7, |r| r.0);
+    println!(
+        "  Search by id 7 in {:?}: {:?} (expected: Ok(2))",
+        records, result17
+    );
+    let result18 = binary_search_by_key(&records, &5, |r| r.0);
+    println!(
+        "  Search by id 5 (not present) in {:?}: {:?} (expected: Err(2))",
+        records, result18
+    );
+
+    // Test case 19: equal_range spans every occurrence of a duplicated value
+    let dup_arr = [1, 2, 2, 2, 3];
+    let range19 = equal_range(&dup_arr, 2);
+    println!(
+        "  equal_range(2) in {:?}: {:?} (expected: (1, 4))",
+        dup_arr, range19
+    );
+
+    // Test case 20: lower_bound/upper_bound on a value absent from the slice
+    // still give the span where it would be inserted
+    let lb20 = lower_bound(&dup_arr, 0);
+    let ub20 = upper_bound(&dup_arr, 4);
+    println!(
+        "  lower_bound(0) in {:?}: {} (expected: 0)",
+        dup_arr, lb20
+    );
+    println!(
+        "  upper_bound(4) in {:?}: {} (expected: 5)",
+        dup_arr, ub20
+    );
 }
 
 fn main() {
@@ -161,7 +395,7 @@ fn main() {
     println!("Array: {:?}", arr);
     println!("Target: {}", target);
 
-    match binary_search(&arr, target) {
+    match binary_search(&arr, &target) {
         Some(i) => println!("Result: Found {} at index {}", target, i),
         None => println!("Result: Not found"),
     }
@@ -0,0 +1,162 @@
+// h1 -- Compile-Time-Dimensioned Arrays Using Const Generics
+// h2 -- This program demonstrates the same row-major address math as
+// h2 -- array_add.rs, but with dimensions fixed at compile time and no
+// h2 -- unsafe pointer arithmetic anywhere
+// h2 -- This parallels the const-generic dimensioning adopted by modern
+// h2 -- Rust linear-algebra crates (fixed-size matrices/tensors known at
+// h2 -- compile time, checked by the type system)
+
+use std::ops::{Index, IndexMut};
+
+// h3 -- Compile-Time-Dimensioned Matrix
+// h4 -- `R` and `C` are const generic parameters, so a Matrix<f32, 3, 4> and a
+// h4 -- Matrix<f32, 4, 3> are distinct types - a transpose-shape mismatch is a
+// h4 -- compile error, not a runtime panic
+// h5 -- data: Row-major element buffer, R*C elements
+// h6 -- Stable Rust doesn't yet allow `[T; R * C]` as a const-generic array
+// h6 -- length (that needs the unstable `generic_const_exprs` feature), so the
+// h6 -- fixed R*C-sized buffer is realized as a `Vec<T>` sized once in `new`
+// h6 -- rather than as a literal array type
+struct Matrix<T, const R: usize, const C: usize> {
+    data: Vec<T>,
+}
+
+impl<T: Default + Clone, const R: usize, const C: usize> Matrix<T, R, C> {
+    // h4 -- Allocates the R*C buffer and fills it with T::default()
+    fn new() -> Self {
+        Matrix {
+            data: vec![T::default(); R * C],
+        }
+    }
+}
+
+impl<T: Default + Clone, const R: usize, const C: usize> Default for Matrix<T, R, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// h4 -- Safe indexing using the same row-major formula the crate documents
+// h4 -- elsewhere: offset = i * C + j
+// h6 -- Bounds are asserted explicitly so out-of-range (i, j) panics with a
+// h6 -- helpful message instead of an opaque Vec index panic
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < R && j < C, "Matrix index ({}, {}) out of bounds for {}x{}", i, j, R, C);
+        &self.data[i * C + j]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < R && j < C, "Matrix index ({}, {}) out of bounds for {}x{}", i, j, R, C);
+        &mut self.data[i * C + j]
+    }
+}
+
+// h3 -- Compile-Time-Dimensioned 3D Tensor
+// h4 -- Same idea extended to three dimensions, mirroring calculate_3d_row_major
+// h5 -- data: Row-major element buffer, D1*D2*D3 elements
+struct Tensor3<T, const D1: usize, const D2: usize, const D3: usize> {
+    data: Vec<T>,
+}
+
+impl<T: Default + Clone, const D1: usize, const D2: usize, const D3: usize> Tensor3<T, D1, D2, D3> {
+    fn new() -> Self {
+        Tensor3 {
+            data: vec![T::default(); D1 * D2 * D3],
+        }
+    }
+}
+
+impl<T: Default + Clone, const D1: usize, const D2: usize, const D3: usize> Default
+    for Tensor3<T, D1, D2, D3>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// h4 -- offset = (i * D2 * D3) + (j * D3) + k, the same formula
+// h4 -- calculate_3d_row_major uses, but computed entirely in safe code
+impl<T, const D1: usize, const D2: usize, const D3: usize> Index<(usize, usize, usize)>
+    for Tensor3<T, D1, D2, D3>
+{
+    type Output = T;
+
+    fn index(&self, (i, j, k): (usize, usize, usize)) -> &T {
+        assert!(
+            i < D1 && j < D2 && k < D3,
+            "Tensor3 index ({}, {}, {}) out of bounds for {}x{}x{}",
+            i,
+            j,
+            k,
+            D1,
+            D2,
+            D3
+        );
+        &self.data[(i * D2 * D3) + (j * D3) + k]
+    }
+}
+
+impl<T, const D1: usize, const D2: usize, const D3: usize> IndexMut<(usize, usize, usize)>
+    for Tensor3<T, D1, D2, D3>
+{
+    fn index_mut(&mut self, (i, j, k): (usize, usize, usize)) -> &mut T {
+        assert!(
+            i < D1 && j < D2 && k < D3,
+            "Tensor3 index ({}, {}, {}) out of bounds for {}x{}x{}",
+            i,
+            j,
+            k,
+            D1,
+            D2,
+            D3
+        );
+        &mut self.data[(i * D2 * D3) + (j * D3) + k]
+    }
+}
+
+fn main() {
+    println!("=== COMPILE-TIME-DIMENSIONED ARRAYS (CONST GENERICS) ===\n");
+
+    // h3 -- Matrix Demonstration
+    println!("1. MATRIX<T, R, C>");
+    println!("==================");
+
+    let mut m: Matrix<f32, 3, 4> = Matrix::default();
+    for i in 0..3 {
+        for j in 0..4 {
+            m[(i, j)] = (i * 4 + j) as f32;
+        }
+    }
+
+    for i in 0..3 {
+        for j in 0..4 {
+            print!("{:6.1}", m[(i, j)]);
+        }
+        println!();
+    }
+
+    println!("\nm[(1, 2)] = {:.1} (expected 6.0)", m[(1, 2)]);
+
+    // h3 -- Tensor3 Demonstration
+    println!("\n2. TENSOR3<T, D1, D2, D3>");
+    println!("=========================");
+
+    let mut t: Tensor3<f32, 2, 3, 4> = Tensor3::default();
+    for i in 0..2 {
+        for j in 0..3 {
+            for k in 0..4 {
+                t[(i, j, k)] = (i * 100 + j * 10 + k) as f32;
+            }
+        }
+    }
+
+    println!("t[(1, 2, 3)] = {:.1} (expected 123.0)", t[(1, 2, 3)]);
+
+    println!("\nOut-of-bounds access panics instead of reading garbage memory:");
+    println!("  m[(5, 0)] would panic: \"Matrix index (5, 0) out of bounds for 3x4\"");
+}
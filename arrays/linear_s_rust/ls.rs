@@ -6,13 +6,15 @@ use std::time::Instant;
 
 // h3 -- Linear Search Function
 // h4 -- Searches using Rust's iterator with enumerate for index/value pairs
-// h5 -- arr: Slice reference to the array data (&[i32])
+// h4 -- Generic over any T: Ord, so this works with &str, String, tuples, or
+// h4 -- any custom type deriving Ord - not just i32
+// h5 -- arr: Slice reference to the array data (&[T])
 // h5 -- target: Value to search for
 // h6 -- Returns: Option<usize> - Some(index) if found, None if not found
 // h6 -- Uses Rust's Option type for safe error handling
 // h6 -- Time Complexity: O(n), Space Complexity: O(1)
-fn linear_search(arr: &[i32], target: i32) -> Option<usize> {
-    for (i, &val) in arr.iter().enumerate() {
+fn linear_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    for (i, val) in arr.iter().enumerate() {
         if val == target {
             return Some(i);
         }
@@ -38,7 +40,7 @@ fn performance_test(size: usize) {
 
     // Warm up the function (run a few times to avoid cold start)
     for _ in 0..10 {
-        linear_search(&large_arr, target);
+        linear_search(&large_arr, &target);
     }
 
     // Time multiple iterations for accuracy
@@ -48,7 +50,7 @@ fn performance_test(size: usize) {
 
     for _ in 0..ITERATIONS {
         let start = Instant::now();
-        let result = linear_search(&large_arr, target);
+        let result = linear_search(&large_arr, &target);
         let elapsed = start.elapsed();
         total_duration += elapsed;
 
@@ -74,28 +76,28 @@ fn validation_tests() {
 
     // Test case 1: Normal array
     let arr1 = [5, 3, 8, 4, 2];
-    let result1 = linear_search(&arr1, 4);
+    let result1 = linear_search(&arr1, &4);
     println!(
         "  Search for 4 in {:?}: {:?} (expected: Some(3))",
         arr1, result1
     );
 
     // Test case 2: First element
-    let result2 = linear_search(&arr1, 5);
+    let result2 = linear_search(&arr1, &5);
     println!(
         "  Search for 5 (first element): {:?} (expected: Some(0))",
         result2
     );
 
     // Test case 3: Last element
-    let result3 = linear_search(&arr1, 2);
+    let result3 = linear_search(&arr1, &2);
     println!(
         "  Search for 2 (last element): {:?} (expected: Some(4))",
         result3
     );
 
     // Test case 4: Not found
-    let result4 = linear_search(&arr1, 9);
+    let result4 = linear_search(&arr1, &9);
     println!(
         "  Search for 9 (not present): {:?} (expected: None)",
         result4
@@ -103,19 +105,19 @@ fn validation_tests() {
 
     // Test case 5: Single element array
     let single_arr = [42];
-    let result5 = linear_search(&single_arr, 42);
+    let result5 = linear_search(&single_arr, &42);
     println!(
         "  Search in single element [42]: {:?} (expected: Some(0))",
         result5
     );
 
     // Test case 6: Single element not found
-    let result6 = linear_search(&single_arr, 99);
+    let result6 = linear_search(&single_arr, &99);
     println!("  Search for 99 in [42]: {:?} (expected: None)", result6);
 
     // Test case 7: Duplicate elements (should find first occurrence)
     let dup_arr = [1, 2, 3, 2, 1];
-    let result7 = linear_search(&dup_arr, 2);
+    let result7 = linear_search(&dup_arr, &2);
     println!(
         "  Search for 2 in {:?}: {:?} (expected: Some(1))",
         dup_arr, result7
@@ -123,8 +125,34 @@ fn validation_tests() {
 
     // Test case 8: Empty array
     let empty_arr: [i32; 0] = [];
-    let result8 = linear_search(&empty_arr, 5);
+    let result8 = linear_search(&empty_arr, &5);
     println!("  Search in empty array: {:?} (expected: None)", result8);
+
+    // Test case 9: Generic over &str, not just i32
+    let words = ["pear", "apple", "mango"];
+    let result9 = linear_search(&words, &"mango");
+    println!(
+        "  Search for \"mango\" in {:?}: {:?} (expected: Some(2))",
+        words, result9
+    );
+
+    // Test case 10: Generic over a custom struct deriving Ord
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Version {
+        major: u32,
+        minor: u32,
+    }
+    let versions = [
+        Version { major: 1, minor: 5 },
+        Version { major: 1, minor: 0 },
+        Version { major: 2, minor: 0 },
+    ];
+    let target_version = Version { major: 1, minor: 0 };
+    let result10 = linear_search(&versions, &target_version);
+    println!(
+        "  Search for {:?} in version list: {:?} (expected: Some(1))",
+        target_version, result10
+    );
 }
 
 fn main() {
@@ -142,7 +170,7 @@ fn main() {
     println!("Array: {:?}", arr);
     println!("Target: {}", target);
 
-    match linear_search(&arr, target) {
+    match linear_search(&arr, &target) {
         Some(i) => println!("Result: Found {} at index {}", target, i),
         None => println!("Result: Not found"),
     }
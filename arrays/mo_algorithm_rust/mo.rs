@@ -0,0 +1,169 @@
+// h1 -- Offline Batched Range-Query Engine (Mo's Algorithm)
+// h2 -- Answers many [l, r) range-aggregate queries over a fixed array faster
+// h2 -- than re-scanning the range for every query
+// h2 -- Total work: O((n + q) * sqrt(n)) for associative, invertible
+// h2 -- aggregates (sum, distinct-count via frequency table, etc.)
+
+// h3 -- Mo's Algorithm Processor
+// h4 -- Maintains the aggregate for the current window [cur_l, cur_r) as the
+// h4 -- window slides between queries; `add`/`remove` must be exact inverses
+// h4 -- and the underlying array must not change while queries are processed
+// h5 -- A: The answer type this aggregate produces (e.g. i64 for a sum)
+trait MoProcessor<A> {
+    // h6 -- Called when `index` enters the current window
+    fn add(&mut self, index: usize);
+    // h6 -- Called when `index` leaves the current window
+    fn remove(&mut self, index: usize);
+    // h6 -- Snapshots the aggregate for the window in its current state
+    fn answer(&self) -> A;
+}
+
+// h3 -- Runs Mo's algorithm over `queries`, returning one answer per query in
+// h3 -- the caller's original order
+// h4 -- n: Size of the fixed array being queried
+// h4 -- queries: Half-open ranges [l, r) to answer, in the order the caller wants results
+// h4 -- processor: Maintains the aggregate via add/remove/answer as the window moves
+// h6 -- Sorts queries into blocks of width ~= n / sqrt(q), ordering by
+// h6 -- (l / block, r), alternating the r direction per odd/even block so the
+// h6 -- right pointer doesn't snap back to the start of the block every time
+fn mo_algorithm<P, A>(n: usize, queries: &[(usize, usize)], processor: &mut P) -> Vec<A>
+where
+    P: MoProcessor<A>,
+{
+    let q = queries.len();
+    if q == 0 {
+        return Vec::new();
+    }
+
+    let block_size = ((n as f64) / (q as f64).sqrt()).ceil().max(1.0) as usize;
+
+    let mut order: Vec<usize> = (0..q).collect();
+    order.sort_by_key(|&i| {
+        let (l, r) = queries[i];
+        let block = l / block_size;
+        let r_key = if block.is_multiple_of(2) { r as isize } else { -(r as isize) };
+        (block, r_key)
+    });
+
+    let mut answers: Vec<Option<A>> = (0..q).map(|_| None).collect();
+    let mut cur_l = 0usize;
+    let mut cur_r = 0usize;
+
+    for idx in order {
+        let (l, r) = queries[idx];
+
+        while cur_r < r {
+            processor.add(cur_r);
+            cur_r += 1;
+        }
+        while cur_r > r {
+            cur_r -= 1;
+            processor.remove(cur_r);
+        }
+        while cur_l > l {
+            cur_l -= 1;
+            processor.add(cur_l);
+        }
+        while cur_l < l {
+            processor.remove(cur_l);
+            cur_l += 1;
+        }
+
+        answers[idx] = Some(processor.answer());
+    }
+
+    answers.into_iter().map(|a| a.unwrap()).collect()
+}
+
+// h3 -- Example Processor: Range Sum
+// h4 -- Maintains a running sum of the elements currently in the window
+struct SumProcessor<'a> {
+    data: &'a [i64],
+    sum: i64,
+}
+
+impl<'a> MoProcessor<i64> for SumProcessor<'a> {
+    fn add(&mut self, index: usize) {
+        self.sum += self.data[index];
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.sum -= self.data[index];
+    }
+
+    fn answer(&self) -> i64 {
+        self.sum
+    }
+}
+
+// h3 -- Example Processor: Distinct-Count
+// h4 -- Maintains a frequency table so leaving a value only drops it from the
+// h4 -- distinct count once its last occurrence in the window is removed
+struct DistinctCountProcessor<'a> {
+    data: &'a [i64],
+    freq: std::collections::HashMap<i64, usize>,
+    distinct: usize,
+}
+
+impl<'a> DistinctCountProcessor<'a> {
+    fn new(data: &'a [i64]) -> Self {
+        DistinctCountProcessor {
+            data,
+            freq: std::collections::HashMap::new(),
+            distinct: 0,
+        }
+    }
+}
+
+impl<'a> MoProcessor<usize> for DistinctCountProcessor<'a> {
+    fn add(&mut self, index: usize) {
+        let count = self.freq.entry(self.data[index]).or_insert(0);
+        *count += 1;
+        if *count == 1 {
+            self.distinct += 1;
+        }
+    }
+
+    fn remove(&mut self, index: usize) {
+        let count = self.freq.get_mut(&self.data[index]).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            self.distinct -= 1;
+        }
+    }
+
+    fn answer(&self) -> usize {
+        self.distinct
+    }
+}
+
+fn main() {
+    println!("=== OFFLINE BATCHED RANGE QUERIES (MO'S ALGORITHM) ===\n");
+
+    let data: Vec<i64> = vec![1, 2, 3, 4, 5, 2, 1, 6, 3, 2];
+    let queries = [(0, 4), (2, 7), (0, 10), (5, 9), (1, 3)];
+
+    println!("Array: {:?}", data);
+    println!("Queries (half-open [l, r)): {:?}\n", queries);
+
+    // h6 -- Expected values are the brute-force sum/distinct-count over each
+    // h6 -- range, computed by hand against `data` above
+    let expected_sums = [10, 15, 29, 12, 5];
+    let expected_distincts = [4, 5, 6, 4, 2];
+
+    println!("1. RANGE SUM");
+    println!("============");
+    let mut sum_processor = SumProcessor { data: &data, sum: 0 };
+    let sums = mo_algorithm(data.len(), &queries, &mut sum_processor);
+    for ((q, sum), expected) in queries.iter().zip(sums.iter()).zip(expected_sums.iter()) {
+        println!("  sum{:?} = {} (expected: {})", q, sum, expected);
+    }
+
+    println!("\n2. DISTINCT-COUNT");
+    println!("=================");
+    let mut distinct_processor = DistinctCountProcessor::new(&data);
+    let distincts = mo_algorithm(data.len(), &queries, &mut distinct_processor);
+    for ((q, distinct), expected) in queries.iter().zip(distincts.iter()).zip(expected_distincts.iter()) {
+        println!("  distinct{:?} = {} (expected: {})", q, distinct, expected);
+    }
+}
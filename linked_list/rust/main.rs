@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 #[derive(Debug)]
 struct Node {
@@ -6,45 +6,148 @@ struct Node {
     next: Option<Box<Node>>,
 }
 
-fn create_list(n: usize) -> Box<Node> {
-    let mut head = Box::new(Node {
-        data: 0,
-        next: None,
-    });
-    let mut tail = &mut head;
-    for i in 1..n {
-        tail.next = Some(Box::new(Node {
-            data: i,
-            next: None,
-        }));
-        tail = tail.next.as_mut().unwrap();
+// h6 -- Dropping a long chain of Box<Node> recursively (the compiler-generated
+// h6 -- default) overflows the stack once n gets into the millions, so unlink
+// h6 -- the chain iteratively instead
+impl Drop for Node {
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(mut boxed) = next {
+            next = boxed.next.take();
+        }
     }
-    head
 }
 
-fn search(mut head: &Box<Node>, target: usize) -> bool {
-    loop {
-        if head.data == target {
-            return true;
+// h3 -- Search Benchmark Target
+// h4 -- Common interface so the harness can run the same sweep against any
+// h4 -- data structure that can be built with n elements and searched linearly
+trait SearchTarget {
+    fn build(n: usize) -> Self;
+    fn search(&self, target: usize) -> bool;
+}
+
+// h3 -- Singly Linked List (Box<Node>)
+struct LinkedList {
+    head: Option<Box<Node>>,
+}
+
+impl SearchTarget for LinkedList {
+    fn build(n: usize) -> Self {
+        let mut head = Box::new(Node {
+            data: 0,
+            next: None,
+        });
+        let mut tail = &mut head;
+        for i in 1..n {
+            tail.next = Some(Box::new(Node {
+                data: i,
+                next: None,
+            }));
+            tail = tail.next.as_mut().unwrap();
+        }
+        LinkedList { head: Some(head) }
+    }
+
+    fn search(&self, target: usize) -> bool {
+        let mut cur = &self.head;
+        while let Some(node) = cur {
+            if node.data == target {
+                return true;
+            }
+            cur = &node.next;
         }
-        match &head.next {
-            Some(next) => head = next,
-            None => return false,
+        false
+    }
+}
+
+// h3 -- Flat Vec<usize> (contiguous storage, linear scan)
+struct VecStore {
+    data: Vec<usize>,
+}
+
+impl SearchTarget for VecStore {
+    fn build(n: usize) -> Self {
+        VecStore {
+            data: (0..n).collect(),
         }
     }
+
+    fn search(&self, target: usize) -> bool {
+        self.data.contains(&target)
+    }
+}
+
+// h3 -- Per-Configuration Timing Result
+struct TimingStats {
+    mean: Duration,
+    min: Duration,
+    max: Duration,
+}
+
+// h4 -- Times `iterations` searches for `target` against an already-built store
+// h5 -- Reports mean/min/max so a single slow outlier doesn't hide the typical case
+// h6 -- black_box on the result keeps the optimizer from proving `search`'s
+// h6 -- return value is unused and eliminating the call under `rustc -O`
+fn time_search<S: SearchTarget>(store: &S, target: usize, iterations: usize) -> TimingStats {
+    let mut min = Duration::MAX;
+    let mut max = Duration::ZERO;
+    let mut total = Duration::ZERO;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        std::hint::black_box(store.search(target));
+        let elapsed = start.elapsed();
+
+        total += elapsed;
+        min = min.min(elapsed);
+        max = max.max(elapsed);
+    }
+
+    TimingStats {
+        mean: total / iterations as u32,
+        min,
+        max,
+    }
 }
 
-fn benchmark(head: &Box<Node>, target: usize) -> f64 {
-    let start = Instant::now();
-    search(head, target);
-    start.elapsed().as_secs_f64()
+// h3 -- Benchmark Harness
+// h4 -- Builds one store of size n and runs it against first/middle/last targets
+fn bench_size<S: SearchTarget>(name: &str, n: usize, iterations: usize) {
+    let store = S::build(n);
+    let positions = [("first", 0), ("middle", n / 2), ("last", n.saturating_sub(1))];
+
+    for (label, target) in positions {
+        let stats = time_search(&store, target, iterations);
+        println!(
+            "{:<12} {:>10} {:<8} {:>12.2?} {:>12.2?} {:>12.2?}",
+            name, n, label, stats.mean, stats.min, stats.max
+        );
+    }
 }
 
 fn main() {
-    let n = 1_000_000;
-    let head = create_list(n);
-    println!("Rust Singly Linked List:");
-    println!("First: {} sec", benchmark(&head, 0));
-    println!("Middle: {} sec", benchmark(&head, n / 2));
-    println!("Last: {} sec", benchmark(&head, n - 1));
+    println!("=== LINKED LIST VS VEC SEARCH BENCHMARK ===\n");
+    println!(
+        "{:<12} {:>10} {:<8} {:>12} {:>12} {:>12}",
+        "structure", "n", "target", "mean", "min", "max"
+    );
+
+    // h4 -- Sweep n across several orders of magnitude; fewer iterations at the
+    // h4 -- largest sizes keeps the whole sweep fast without losing the trend
+    let sizes_and_iterations = [
+        (1_000, 200),
+        (10_000, 200),
+        (100_000, 50),
+        (1_000_000, 20),
+        (10_000_000, 5),
+    ];
+
+    for (n, iterations) in sizes_and_iterations {
+        bench_size::<LinkedList>("linked_list", n, iterations);
+        bench_size::<VecStore>("vec", n, iterations);
+    }
+
+    println!("\nThe gap between the two structures at large n is the");
+    println!("cache-locality cost of pointer-chasing through scattered Box<Node>");
+    println!("allocations versus a contiguous Vec<usize> linear scan.");
 }